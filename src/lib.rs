@@ -15,6 +15,136 @@ pub struct DBViewMut<'a,T> where T : 'a {
     entries: Vec<&'a mut T>,
 }
 
+/// The result of projecting a `DBView`'s entries through a transform, owning
+/// the computed values rather than borrowing from the originating `DB`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProjectedView<U> {
+    entries: Vec<U>,
+}
+
+impl<U> ProjectedView<U> {
+    /// Returns the number of entries in the projection.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the projection has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the projected values as a slice.
+    pub fn as_slice(&self) -> &[U] {
+        &self.entries
+    }
+
+    /// Consumes the projection, returning its entries as a `Vec`.
+    pub fn into_vec(self) -> Vec<U> {
+        self.entries
+    }
+}
+
+impl<U> FromIterator<U> for ProjectedView<U> {
+    fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> ProjectedView<U> {
+        ProjectedView { entries: iter.into_iter().collect() }
+    }
+}
+
+/// Error produced while validating a `Predicate` tree.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PredicateError {
+    /// A `Not` directly wrapping another `Not`, which should be simplified to
+    /// its inner predicate instead of built this way.
+    DoubleNot,
+}
+
+impl std::fmt::Display for PredicateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PredicateError::DoubleNot => write!(f, "redundant Not(Not(..)) nesting"),
+        }
+    }
+}
+
+impl std::error::Error for PredicateError {}
+
+/// A predicate over `T`, built up as data instead of closing over logic in a
+/// single closure. Predicates can be combined with `and`/`or`/`not`, reused
+/// across queries, and evaluated with `evaluate`.
+pub enum Predicate<T> {
+    /// A leaf predicate backed by an arbitrary closure.
+    Leaf(Box<dyn Fn(&T) -> bool>),
+    /// Holds when both sub-predicates hold.
+    And(Box<Predicate<T>>, Box<Predicate<T>>),
+    /// Holds when either sub-predicate holds.
+    Or(Box<Predicate<T>>, Box<Predicate<T>>),
+    /// Holds when the sub-predicate does not.
+    Not(Box<Predicate<T>>),
+}
+
+impl<T> Predicate<T> {
+    /// Wraps a closure as a `Predicate`.
+    pub fn leaf<F>(predicate: F) -> Predicate<T>
+        where F: Fn(&T) -> bool + 'static
+    {
+        Predicate::Leaf(Box::new(predicate))
+    }
+
+    /// Requires both `self` and `other` to hold.
+    pub fn and(self, other: Predicate<T>) -> Predicate<T> {
+        Predicate::And(Box::new(self), Box::new(other))
+    }
+
+    /// Requires either `self` or `other` to hold.
+    pub fn or(self, other: Predicate<T>) -> Predicate<T> {
+        Predicate::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Negates `self`.
+    #[allow(clippy::should_implement_trait)] // mirrors the `Not` variant name, not std::ops::Not
+    pub fn not(self) -> Predicate<T> {
+        Predicate::Not(Box::new(self))
+    }
+
+    /// Combines `predicates`, requiring all of them to hold.
+    pub fn all(predicates: Vec<Predicate<T>>) -> Predicate<T> {
+        predicates.into_iter().fold(Predicate::leaf(|_: &T| true), Predicate::and)
+    }
+
+    /// Combines `predicates`, requiring none of them to hold.
+    pub fn none(predicates: Vec<Predicate<T>>) -> Predicate<T> {
+        predicates.into_iter().fold(Predicate::leaf(|_: &T| false), Predicate::or).not()
+    }
+
+    /// Recursively evaluates this predicate against `value`.
+    pub fn evaluate(&self, value: &T) -> bool {
+        match self {
+            Predicate::Leaf(f) => f(value),
+            Predicate::And(a, b) => a.evaluate(value) && b.evaluate(value),
+            Predicate::Or(a, b) => a.evaluate(value) || b.evaluate(value),
+            Predicate::Not(p) => !p.evaluate(value),
+        }
+    }
+
+    /// Rejects a directly nested `Not(Not(..))` anywhere in this predicate,
+    /// since it should be simplified to its inner predicate instead.
+    pub fn check_double_not(&self) -> Result<(), PredicateError> {
+        match self {
+            Predicate::Not(inner) => {
+                if let Predicate::Not(_) = inner.as_ref() {
+                    return Err(PredicateError::DoubleNot);
+                }
+                inner.check_double_not()
+            }
+            Predicate::And(a, b) | Predicate::Or(a, b) => {
+                a.check_double_not()?;
+                b.check_double_not()
+            }
+            Predicate::Leaf(_) => Ok(()),
+        }
+    }
+}
+
 /// Filters a DBView using the the given predicate.
 pub fn filter_one<'a,T,F>(view: &DBView<'a,T>, predicate: F) -> DBView<'a,T>
     where F: Fn(&T) -> bool
@@ -33,6 +163,103 @@ pub fn filter_two<'a,'b,T,F>(view_a: &DBView<'a,T>,
     (view_a.select_where(&predicate), view_b.select_where(&predicate))
 }
 
+/// A lazy iterator adapter over borrowed entries, analogous to the standard
+/// library's `Filter`. Only entries satisfying `predicate` are yielded, and
+/// nothing is collected until the caller consumes the iterator.
+pub struct DBIter<'a, T: 'a, I, P> where I: Iterator<Item = &'a T> {
+    iter: I,
+    predicate: P,
+}
+
+impl<'a, T: 'a, I, P> DBIter<'a, T, I, P>
+    where I: Iterator<Item = &'a T>, P: Fn(&T) -> bool
+{
+    fn new(iter: I, predicate: P) -> DBIter<'a, T, I, P> {
+        DBIter { iter, predicate }
+    }
+}
+
+impl<'a, T: 'a, I, P> Iterator for DBIter<'a, T, I, P>
+    where I: Iterator<Item = &'a T>, P: Fn(&T) -> bool
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let predicate = &self.predicate;
+        self.iter.find(|t| predicate(t))
+    }
+
+    /// Applies the predicate inline while folding, avoiding the
+    /// intermediate allocation a `.filter(..).fold(..)` chain would need.
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+        where F: FnMut(B, Self::Item) -> B
+    {
+        let predicate = self.predicate;
+        self.iter.fold(init, |acc, t| if predicate(t) { f(acc, t) } else { acc })
+    }
+
+    // `Iterator::try_fold` can't be overridden here: its signature is bound by
+    // `R: std::ops::Try`, and that trait is still gated behind the unstable
+    // `try_trait_v2` feature, so a stable crate can't spell out a matching
+    // override. `find`/`position`/`any`/`all` all short-circuit through
+    // `try_fold` by default, which would otherwise fall back to repeatedly
+    // calling `next`, so they're overridden individually below instead:
+    // `find`/`any`/`all` delegate straight to the inner iterator's own
+    // (possibly specialized) implementation with the predicate applied
+    // inline, and `position` loops over the inner iterator itself.
+
+    fn find<F2>(&mut self, mut f: F2) -> Option<Self::Item>
+        where F2: FnMut(&Self::Item) -> bool
+    {
+        let predicate = &self.predicate;
+        self.iter.find(|t| predicate(*t) && f(t))
+    }
+
+    fn position<F2>(&mut self, mut f: F2) -> Option<usize>
+        where F2: FnMut(Self::Item) -> bool
+    {
+        // Can't delegate to `self.iter.position` here like `find`/`any`/`all`
+        // do: that counts every element of the *underlying* iterator, but
+        // `position`'s contract is to index into what `self` actually
+        // yields, i.e. only the elements that pass `predicate`.
+        let predicate = &self.predicate;
+        let mut index = 0;
+        for t in self.iter.by_ref() {
+            if predicate(t) {
+                if f(t) {
+                    return Some(index);
+                }
+                index += 1;
+            }
+        }
+        None
+    }
+
+    fn any<F2>(&mut self, mut f: F2) -> bool
+        where F2: FnMut(Self::Item) -> bool
+    {
+        let predicate = &self.predicate;
+        self.iter.any(|t| predicate(t) && f(t))
+    }
+
+    fn all<F2>(&mut self, mut f: F2) -> bool
+        where F2: FnMut(Self::Item) -> bool
+    {
+        let predicate = &self.predicate;
+        self.iter.all(|t| !predicate(t) || f(t))
+    }
+}
+
+impl<'a, T: 'a, I, P> std::iter::FusedIterator for DBIter<'a, T, I, P>
+    where I: Iterator<Item = &'a T> + std::iter::FusedIterator, P: Fn(&T) -> bool
+{}
+
+impl<'a, T> FromIterator<&'a T> for DBView<'a, T> {
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> DBView<'a, T> {
+        DBView { entries: iter.into_iter().collect() }
+    }
+}
+
 impl<T> DB<T> {
     /// Creates a DB from the given list of entries
     pub fn new(data: Vec<T>) -> DB<T> {
@@ -43,7 +270,15 @@ impl<T> DB<T> {
     pub fn select_where<F>(&self, predicate: F) -> DBView<T>
         where F: Fn(&T) -> bool
     {
-        DBView { entries: self.data.iter().filter(|t| predicate(t)).collect() }
+        self.iter_where(predicate).collect()
+    }
+
+    /// Returns a lazy iterator over entries in `self` which satisfy `predicate`,
+    /// without collecting into an intermediate `Vec`.
+    pub fn iter_where<F>(&self, predicate: F) -> DBIter<'_, T, std::slice::Iter<'_, T>, F>
+        where F: Fn(&T) -> bool
+    {
+        DBIter::new(self.data.iter(), predicate)
     }
 
     /// Creates a new DBView containing all entries in `self` which satisfy `predicate`
@@ -67,6 +302,152 @@ impl<T> DB<T> {
     pub fn len(&self) -> usize {
         self.data.len()
     }
+
+    /// Creates a new DBView containing all entries in `self` matching `predicate`
+    pub fn query(&self, predicate: &Predicate<T>) -> DBView<'_, T> {
+        self.select_where(|t| predicate.evaluate(t))
+    }
+
+    /// Selects entries in `self` satisfying `predicate` and projects each through
+    /// `f` in the same pass, instead of collecting a `DBView` and projecting it
+    /// separately.
+    pub fn select_where_project<U, P, F>(&self, predicate: P, f: F) -> ProjectedView<U>
+        where P: Fn(&T) -> bool, F: Fn(&T) -> U
+    {
+        self.iter_where(predicate).map(f).collect()
+    }
+
+    /// Builds a secondary index over `self`, mapping each distinct key value
+    /// `key_fn` extracts to the positions of the entries holding it. Querying
+    /// through the index with `select_indexed` avoids the O(n) scan that
+    /// `select_where` does, at the cost of the O(n log n) build up front.
+    ///
+    /// The returned `DBIndex` is only valid against `self` as it stood at build
+    /// time; see the invariant documented on `DBIndex`.
+    pub fn build_index<K, F>(&self, key_fn: F) -> DBIndex<T, K>
+        where F: Fn(&T) -> K, K: Ord
+    {
+        let mut postings: std::collections::BTreeMap<K, Vec<usize>> = std::collections::BTreeMap::new();
+        for (position, entry) in self.data.iter().enumerate() {
+            postings.entry(key_fn(entry)).or_default().push(position);
+        }
+        DBIndex { source_len: self.data.len(), postings, marker: std::marker::PhantomData }
+    }
+
+    /// Creates a new DBView containing the entries in `self` whose key in `index`
+    /// equals `key`, without scanning entries that don't match.
+    ///
+    /// Panics if `self.len()` differs from the length recorded when `index` was
+    /// built; an index built from a different `DB` of the same length is not
+    /// detected. See `DBIndex`.
+    pub fn select_indexed<K: Ord>(&self, index: &DBIndex<T, K>, key: &K) -> DBView<'_, T> {
+        assert_eq!(self.data.len(), index.source_len,
+                   "DBIndex was built from a DB of a different length and is no longer valid");
+        let entries = match index.postings.get(key) {
+            Some(positions) => positions.iter().map(|&position| &self.data[position]).collect(),
+            None => Vec::new(),
+        };
+        DBView { entries }
+    }
+
+    /// Creates a new DBView containing all entries in `self` whose key (as
+    /// extracted by `key_fn`) satisfies `bound`, without every caller rewriting
+    /// the same comparison closure.
+    pub fn select_range<K, F>(&self, key_fn: F, bound: Bound<K>) -> DBView<'_, T>
+        where F: Fn(&T) -> K, K: PartialOrd
+    {
+        self.select_where(|t| bound.matches(&key_fn(t)))
+    }
+
+    /// Like `select_range`, but scans only the matching portion of `index`'s
+    /// `BTreeMap` instead of testing every entry, for an O(log n + m) lookup.
+    ///
+    /// Panics if `self.len()` differs from the length recorded when `index` was
+    /// built; see `DBIndex`.
+    pub fn select_range_indexed<K: Ord + Clone>(&self, index: &DBIndex<T, K>, bound: Bound<K>) -> DBView<'_, T> {
+        assert_eq!(self.data.len(), index.source_len,
+                   "DBIndex was built from a DB of a different length and is no longer valid");
+        let entries = index.postings.range(bound.to_range_bounds())
+            .flat_map(|(_, positions)| positions.iter())
+            .map(|&position| &self.data[position])
+            .collect();
+        DBView { entries }
+    }
+}
+
+/// A bound tested against a key extracted from an entry, for use with
+/// `select_range`. `Inclusive`/`Exclusive` describe an ordered cutoff (entries
+/// keyed at most / strictly less than the given value); `Exact` tests equality
+/// only, and is also what the `boolean`/`unit`/`null` constructors below build.
+pub enum Bound<K> {
+    /// Matches entries whose key is less than or equal to the given value.
+    Inclusive(K),
+    /// Matches entries whose key is strictly less than the given value.
+    Exclusive(K),
+    /// Matches entries whose key equals the given value exactly.
+    Exact(K),
+}
+
+impl<K: PartialOrd> Bound<K> {
+    /// Tests `key` against this bound.
+    pub fn matches(&self, key: &K) -> bool {
+        match self {
+            Bound::Inclusive(bound) => key <= bound,
+            Bound::Exclusive(bound) => key < bound,
+            Bound::Exact(bound) => key == bound,
+        }
+    }
+}
+
+impl<K: Ord + Clone> Bound<K> {
+    /// Converts this bound into the `(start, end)` form `BTreeMap::range`
+    /// expects, for `select_range_indexed`'s indexed scan.
+    fn to_range_bounds(&self) -> (std::ops::Bound<K>, std::ops::Bound<K>) {
+        match self {
+            Bound::Inclusive(k) => (std::ops::Bound::Unbounded, std::ops::Bound::Included(k.clone())),
+            Bound::Exclusive(k) => (std::ops::Bound::Unbounded, std::ops::Bound::Excluded(k.clone())),
+            Bound::Exact(k) => (std::ops::Bound::Included(k.clone()), std::ops::Bound::Included(k.clone())),
+        }
+    }
+}
+
+impl Bound<bool> {
+    /// Matches entries whose key is exactly `value`. A named alternative to
+    /// `Exact` for `bool` keys, which have no meaningful order.
+    pub fn boolean(value: bool) -> Bound<bool> {
+        Bound::Exact(value)
+    }
+}
+
+impl Bound<()> {
+    /// Matches entries whose key is `()`. A named alternative to `Exact` for
+    /// unit keys, which have only one possible value.
+    pub fn unit() -> Bound<()> {
+        Bound::Exact(())
+    }
+}
+
+impl<U: PartialOrd> Bound<Option<U>> {
+    /// Matches entries whose key is `None`. A named alternative to `Exact`
+    /// for `Option<_>` keys where only presence/absence matters.
+    pub fn null() -> Bound<Option<U>> {
+        Bound::Exact(None)
+    }
+}
+
+/// A secondary index over a `DB<T>`, mapping each distinct key value (as
+/// extracted by the closure passed to `DB::build_index`) to the positions in
+/// `data` holding that key.
+///
+/// Only valid against the exact `DB` it was built from; see `select_indexed`.
+/// Staleness is only detected by length via `source_len`, so an in-place
+/// mutation that changes a row's key without changing `len()` (e.g. through
+/// `DB::as_view_mut`/`select_where_mut`) will silently desync the index —
+/// rebuild it whenever the underlying data changes.
+pub struct DBIndex<T, K> {
+    source_len: usize,
+    postings: std::collections::BTreeMap<K, Vec<usize>>,
+    marker: std::marker::PhantomData<fn(&T)>,
 }
 
 impl<'a,T> DBView<'a,T> {
@@ -74,13 +455,181 @@ impl<'a,T> DBView<'a,T> {
     pub fn select_where<F>(&self, predicate: F) -> DBView<'a,T>
         where F: Fn(&T) -> bool
     {
-        DBView { entries: self.entries.iter().map(|t| *t).filter(|t| predicate(t)).collect() }
+        self.iter_where(predicate).collect()
+    }
+
+    /// Returns a lazy iterator over entries in `self` which satisfy `predicate`,
+    /// without collecting into an intermediate `Vec`.
+    pub fn iter_where<F>(&self, predicate: F) -> DBIter<'a, T, std::iter::Copied<std::slice::Iter<'_, &'a T>>, F>
+        where F: Fn(&T) -> bool
+    {
+        DBIter::new(self.entries.iter().copied(), predicate)
+    }
+
+    /// Creates a new DBView containing all entries in `self` matching `predicate`
+    pub fn query(&self, predicate: &Predicate<T>) -> DBView<'a,T> {
+        self.select_where(|t| predicate.evaluate(t))
+    }
+
+    /// Transforms every entry in `self` through `f`, producing an owned
+    /// `ProjectedView` rather than narrowing which entries are selected.
+    pub fn project<U, F>(&self, f: F) -> ProjectedView<U>
+        where F: Fn(&T) -> U
+    {
+        self.entries.iter().map(|t| f(t)).collect()
+    }
+
+    /// Selects entries in `self` satisfying `predicate` and projects each through
+    /// `f` in the same pass, instead of collecting a `DBView` and projecting it
+    /// separately.
+    pub fn select_where_project<U, P, F>(&self, predicate: P, f: F) -> ProjectedView<U>
+        where P: Fn(&T) -> bool, F: Fn(&T) -> U
+    {
+        self.iter_where(predicate).map(f).collect()
+    }
+
+    /// Creates a new DBView containing all entries in `self` whose key (as
+    /// extracted by `key_fn`) satisfies `bound`.
+    pub fn select_range<K, F>(&self, key_fn: F, bound: Bound<K>) -> DBView<'a,T>
+        where F: Fn(&T) -> K, K: PartialOrd
+    {
+        self.select_where(|t| bound.matches(&key_fn(t)))
     }
 
     /// Returns the number of entries in the DBView
     pub fn len(&self) -> usize {
         self.entries.len()
     }
+
+    /// Returns a view containing every entry in `self` or `other`, counting
+    /// entries present in both only once. Identity is decided by pointer
+    /// equality of the borrowed references, not `T: Eq`.
+    ///
+    /// **Caveat:** for a zero-sized `T` (e.g. `()`, or a unit struct), every
+    /// reference shares the same dangling, non-null address, so pointer
+    /// identity can't distinguish otherwise-distinct rows — all of them
+    /// collapse into at most one entry. There's no `T: Eq`/`T: Hash` bound
+    /// available to fall back on here, so this is a fundamental limitation
+    /// of identity-by-address rather than a bug to fix; avoid these set
+    /// operators on views over a zero-sized `T`.
+    pub fn union(&self, other: &DBView<'a,T>) -> DBView<'a,T> {
+        let mut entries: Vec<&'a T> =
+            self.entries.iter().chain(other.entries.iter()).copied().collect();
+        dedup_by_ptr(&mut entries);
+        DBView { entries }
+    }
+
+    /// Returns a view containing the entries in `self` that are also in `other`.
+    ///
+    /// Identity is decided by pointer equality; see the caveat on `union`
+    /// about zero-sized `T`.
+    pub fn intersection(&self, other: &DBView<'a,T>) -> DBView<'a,T> {
+        let other_ptrs: std::collections::BTreeSet<*const T> =
+            other.entries.iter().map(|t| *t as *const T).collect();
+        DBView {
+            entries: self.entries.iter()
+                .copied()
+                .filter(|t| other_ptrs.contains(&(*t as *const T)))
+                .collect(),
+        }
+    }
+
+    /// Returns a view containing the entries in `self` that are not in `other`.
+    ///
+    /// Identity is decided by pointer equality; see the caveat on `union`
+    /// about zero-sized `T`.
+    pub fn difference(&self, other: &DBView<'a,T>) -> DBView<'a,T> {
+        let other_ptrs: std::collections::BTreeSet<*const T> =
+            other.entries.iter().map(|t| *t as *const T).collect();
+        DBView {
+            entries: self.entries.iter()
+                .copied()
+                .filter(|t| !other_ptrs.contains(&(*t as *const T)))
+                .collect(),
+        }
+    }
+
+    /// Returns a view containing the entries that are in exactly one of `self` or `other`.
+    ///
+    /// Identity is decided by pointer equality; see the caveat on `union`
+    /// about zero-sized `T`.
+    pub fn symmetric_difference(&self, other: &DBView<'a,T>) -> DBView<'a,T> {
+        self.difference(other).union(&other.difference(self))
+    }
+
+    /// Returns a view with `self`'s entries reordered by the key `key_fn` extracts,
+    /// ascending. `K` need only be `Ord`, not `T` itself, so callers can sort by any
+    /// comparable field without requiring `T: Ord`.
+    pub fn sort_by_key<K, L>(&self, key_fn: L) -> DBView<'a,T>
+        where L: Fn(&T) -> K, K: Ord
+    {
+        let mut entries = self.entries.clone();
+        entries.sort_by_key(|t| key_fn(t));
+        DBView { entries }
+    }
+
+    /// Like `sort_by_key`, but using an unstable sort for entries that don't need to
+    /// preserve the relative order of equal keys.
+    pub fn sort_unstable_by_key<K, L>(&self, key_fn: L) -> DBView<'a,T>
+        where L: Fn(&T) -> K, K: Ord
+    {
+        let mut entries = self.entries.clone();
+        entries.sort_unstable_by_key(|t| key_fn(t));
+        DBView { entries }
+    }
+
+    /// Returns a view with `self`'s entries reordered by the full comparator `compare`,
+    /// for cases where the sort key borrows from the element rather than being owned.
+    pub fn sort_by<F>(&self, compare: F) -> DBView<'a,T>
+        where F: Fn(&T, &T) -> std::cmp::Ordering
+    {
+        let mut entries = self.entries.clone();
+        entries.sort_by(|a, b| compare(a, b));
+        DBView { entries }
+    }
+}
+
+/// Deduplicates `entries` by the pointer address of each reference, keeping
+/// the first occurrence and preserving order.
+///
+/// For a zero-sized `T`, every reference has the same address, so this
+/// collapses all entries down to at most one; see the caveat documented on
+/// `DBView::union`.
+fn dedup_by_ptr<T>(entries: &mut Vec<&T>) {
+    let mut seen = std::collections::BTreeSet::new();
+    entries.retain(|t| seen.insert(*t as *const T));
+}
+
+impl<'a,T> std::ops::BitOr for &DBView<'a,T> {
+    type Output = DBView<'a,T>;
+
+    fn bitor(self, other: &DBView<'a,T>) -> DBView<'a,T> {
+        self.union(other)
+    }
+}
+
+impl<'a,T> std::ops::BitAnd for &DBView<'a,T> {
+    type Output = DBView<'a,T>;
+
+    fn bitand(self, other: &DBView<'a,T>) -> DBView<'a,T> {
+        self.intersection(other)
+    }
+}
+
+impl<'a,T> std::ops::Sub for &DBView<'a,T> {
+    type Output = DBView<'a,T>;
+
+    fn sub(self, other: &DBView<'a,T>) -> DBView<'a,T> {
+        self.difference(other)
+    }
+}
+
+impl<'a,T> std::ops::BitXor for &DBView<'a,T> {
+    type Output = DBView<'a,T>;
+
+    fn bitxor(self, other: &DBView<'a,T>) -> DBView<'a,T> {
+        self.symmetric_difference(other)
+    }
 }
 
 impl<'a,T> DBViewMut<'a,T> {
@@ -95,6 +644,33 @@ impl<'a,T> DBViewMut<'a,T> {
     pub fn len(&self) -> usize {
         self.entries.len()
     }
+
+    /// Returns `self` with its entries reordered by the key `key_fn` extracts,
+    /// ascending. `K` need only be `Ord`, not `T` itself.
+    pub fn sort_by_key<K, L>(mut self, key_fn: L) -> DBViewMut<'a,T>
+        where L: Fn(&T) -> K, K: Ord
+    {
+        self.entries.sort_by_key(|t| key_fn(t));
+        self
+    }
+
+    /// Like `sort_by_key`, but using an unstable sort for entries that don't need to
+    /// preserve the relative order of equal keys.
+    pub fn sort_unstable_by_key<K, L>(mut self, key_fn: L) -> DBViewMut<'a,T>
+        where L: Fn(&T) -> K, K: Ord
+    {
+        self.entries.sort_unstable_by_key(|t| key_fn(t));
+        self
+    }
+
+    /// Returns `self` with its entries reordered by the full comparator `compare`,
+    /// for cases where the sort key borrows from the element rather than being owned.
+    pub fn sort_by<F>(mut self, compare: F) -> DBViewMut<'a,T>
+        where F: Fn(&T, &T) -> std::cmp::Ordering
+    {
+        self.entries.sort_by(|a, b| compare(a, b));
+        self
+    }
 }
 
 // Bonus A
@@ -123,3 +699,306 @@ impl<'a,T> DBViewMut<'a,T> {
 //     type Item = &mut T;
 //     // TODO
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn db_iter_where_yields_only_matching_entries_in_order() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let evens: Vec<i32> = db.iter_where(|&x| x % 2 == 0).copied().collect();
+        assert_eq!(evens, vec![2, 4]);
+    }
+
+    #[test]
+    fn db_iter_fold_sums_only_matching_entries() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let sum = db.iter_where(|&x| x % 2 == 0).sum::<i32>();
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn db_iter_find_returns_first_match_of_both_predicate_and_closure() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let found = db.iter_where(|&x| x % 2 == 0).find(|&&x| x > 2);
+        assert_eq!(found, Some(&4));
+    }
+
+    #[test]
+    fn db_iter_position_indexes_into_the_filtered_sequence() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        // The filtered view is `[2, 4]`, so `4` sits at index 1, not its
+        // index 3 in the unfiltered `[1, 2, 3, 4, 5]`.
+        let position = db.iter_where(|&x| x % 2 == 0).position(|&x| x == 4);
+        assert_eq!(position, Some(1));
+    }
+
+    #[test]
+    fn db_iter_any_and_all_only_consider_matching_entries() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        assert!(db.iter_where(|&x| x % 2 == 0).any(|&x| x == 4));
+        assert!(!db.iter_where(|&x| x % 2 == 0).any(|&x| x == 3));
+        assert!(db.iter_where(|&x| x % 2 == 0).all(|&x| x > 0));
+        assert!(!db.iter_where(|&x| x % 2 == 0).all(|&x| x > 2));
+    }
+
+    #[test]
+    fn predicate_evaluate_and_or_all_none() {
+        let positive = || Predicate::<i32>::leaf(|&x| x > 0);
+        let even = || Predicate::<i32>::leaf(|&x| x % 2 == 0);
+
+        assert!(positive().and(Predicate::leaf(|&x| x < 10)).evaluate(&4));
+        assert!(!positive().or(even()).evaluate(&-3));
+
+        let all_positive_and_even = Predicate::all(vec![
+            Predicate::leaf(|&x: &i32| x > 0),
+            Predicate::leaf(|&x: &i32| x % 2 == 0),
+        ]);
+        assert!(all_positive_and_even.evaluate(&4));
+        assert!(!all_positive_and_even.evaluate(&3));
+
+        let none_negative_or_odd = Predicate::none(vec![
+            Predicate::leaf(|&x: &i32| x < 0),
+            Predicate::leaf(|&x: &i32| x % 2 != 0),
+        ]);
+        assert!(none_negative_or_odd.evaluate(&4));
+        assert!(!none_negative_or_odd.evaluate(&-4));
+        assert!(!none_negative_or_odd.evaluate(&3));
+    }
+
+    #[test]
+    fn db_and_dbview_query_use_predicate() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let even = Predicate::<i32>::leaf(|&x| x % 2 == 0);
+
+        let view = db.query(&even);
+        assert_eq!(view.len(), 2);
+
+        let narrowed = view.query(&Predicate::leaf(|&x: &i32| x > 2));
+        assert_eq!(narrowed.len(), 1);
+    }
+
+    fn sorted_values(view: &DBView<i32>) -> Vec<i32> {
+        let mut values: Vec<i32> = view.iter_where(|_| true).copied().collect();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn set_ops_on_ordinary_entries() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let low = db.select_where(|&x| x <= 3);
+        let high = db.select_where(|&x| x >= 3);
+
+        assert_eq!(sorted_values(&low.union(&high)), vec![1, 2, 3, 4, 5]);
+        assert_eq!(sorted_values(&low.intersection(&high)), vec![3]);
+        assert_eq!(sorted_values(&low.difference(&high)), vec![1, 2]);
+        assert_eq!(sorted_values(&low.symmetric_difference(&high)), vec![1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn set_op_operators_match_named_methods() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let low = db.select_where(|&x| x <= 3);
+        let high = db.select_where(|&x| x >= 3);
+
+        assert_eq!(&low | &high, low.union(&high));
+        assert_eq!(&low & &high, low.intersection(&high));
+        assert_eq!(&low - &high, low.difference(&high));
+        assert_eq!(&low ^ &high, low.symmetric_difference(&high));
+    }
+
+    #[test]
+    fn union_of_zero_sized_entries_collapses_to_one() {
+        // Documented limitation: identity-by-pointer can't tell zero-sized
+        // entries apart, since every `&()` shares the same address.
+        let db = DB::new(vec![(), (), ()]);
+        let everything = db.select_where(|_| true);
+        let unioned = everything.union(&everything);
+        assert_eq!(unioned.len(), 1);
+    }
+
+    #[test]
+    fn check_double_not_accepts_single_not() {
+        let predicate = Predicate::<i32>::leaf(|&x| x > 0).not();
+        assert_eq!(predicate.check_double_not(), Ok(()));
+    }
+
+    #[test]
+    fn check_double_not_rejects_direct_double_not() {
+        let predicate = Predicate::<i32>::leaf(|&x| x > 0).not().not();
+        assert_eq!(predicate.check_double_not(), Err(PredicateError::DoubleNot));
+    }
+
+    #[test]
+    fn check_double_not_rejects_double_not_nested_under_and() {
+        let predicate = Predicate::<i32>::leaf(|&x| x > 0)
+            .not().not()
+            .and(Predicate::leaf(|&x| x < 10));
+        assert_eq!(predicate.check_double_not(), Err(PredicateError::DoubleNot));
+    }
+
+    #[test]
+    fn dbview_sort_by_key_reorders_ascending() {
+        let db = DB::new(vec![3, 1, 2]);
+        let sorted = db.as_view().sort_by_key(|&x| x);
+        let values: Vec<i32> = sorted.iter_where(|_| true).copied().collect();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn dbview_sort_unstable_by_key_reorders_ascending() {
+        let db = DB::new(vec![3, 1, 2]);
+        let sorted = db.as_view().sort_unstable_by_key(|&x| x);
+        let values: Vec<i32> = sorted.iter_where(|_| true).copied().collect();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn dbview_sort_by_reorders_using_comparator() {
+        let db = DB::new(vec![3, 1, 2]);
+        let sorted = db.as_view().sort_by(|a, b| b.cmp(a));
+        let values: Vec<i32> = sorted.iter_where(|_| true).copied().collect();
+        assert_eq!(values, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn dbview_mut_sort_by_key_reorders_ascending() {
+        let mut db = DB::new(vec![3, 1, 2]);
+        let sorted = db.as_view_mut().sort_by_key(|&x| x);
+        assert_eq!(sorted.len(), 3);
+        assert_eq!(*sorted.entries[0], 1);
+        assert_eq!(*sorted.entries[1], 2);
+        assert_eq!(*sorted.entries[2], 3);
+    }
+
+    #[test]
+    fn dbview_mut_sort_unstable_by_key_reorders_ascending() {
+        let mut db = DB::new(vec![3, 1, 2]);
+        let sorted = db.as_view_mut().sort_unstable_by_key(|&x| x);
+        assert_eq!(*sorted.entries[0], 1);
+        assert_eq!(*sorted.entries[1], 2);
+        assert_eq!(*sorted.entries[2], 3);
+    }
+
+    #[test]
+    fn dbview_mut_sort_by_reorders_using_comparator() {
+        let mut db = DB::new(vec![3, 1, 2]);
+        let sorted = db.as_view_mut().sort_by(|a, b| b.cmp(a));
+        assert_eq!(*sorted.entries[0], 3);
+        assert_eq!(*sorted.entries[1], 2);
+        assert_eq!(*sorted.entries[2], 1);
+    }
+
+    #[test]
+    fn select_indexed_finds_matching_entries() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let index = db.build_index(|&x| x % 2);
+        let evens = db.select_indexed(&index, &0);
+        assert_eq!(evens.len(), 2);
+    }
+
+    #[test]
+    fn select_indexed_returns_empty_view_for_missing_key() {
+        let db = DB::new(vec![1, 2, 3]);
+        let index = db.build_index(|&x| x % 2);
+        let none = db.select_indexed(&index, &5);
+        assert_eq!(none.len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "DBIndex was built from a DB of a different length")]
+    fn select_indexed_panics_on_stale_index() {
+        let built_from = DB::new(vec![1, 2, 3]);
+        let index = built_from.build_index(|&x| x);
+        let other = DB::new(vec![1, 2]);
+        other.select_indexed(&index, &1);
+    }
+
+    #[test]
+    fn dbview_project_transforms_every_entry() {
+        let db = DB::new(vec![1, 2, 3]);
+        let doubled = db.as_view().project(|&x| x * 2);
+        assert_eq!(doubled.len(), 3);
+        assert!(!doubled.is_empty());
+        assert_eq!(doubled.as_slice(), &[2, 4, 6]);
+        assert_eq!(doubled.into_vec(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn projected_view_is_empty_for_no_entries() {
+        let db: DB<i32> = DB::new(vec![]);
+        let projected = db.as_view().project(|&x| x);
+        assert!(projected.is_empty());
+        assert_eq!(projected.len(), 0);
+    }
+
+    #[test]
+    fn select_where_project_filters_then_projects_in_one_pass() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let doubled_evens = db.select_where_project(|&x| x % 2 == 0, |&x| x * 2);
+        assert_eq!(doubled_evens.as_slice(), &[4, 8]);
+
+        let view_doubled_evens = db.as_view().select_where_project(|&x| x % 2 == 0, |&x| x * 2);
+        assert_eq!(view_doubled_evens.as_slice(), &[4, 8]);
+    }
+
+    #[test]
+    fn select_range_with_inclusive_bound() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        assert_eq!(sorted_values(&db.select_range(|&x| x, Bound::Inclusive(3))), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn select_range_with_exclusive_bound() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        assert_eq!(sorted_values(&db.select_range(|&x| x, Bound::Exclusive(3))), vec![1, 2]);
+    }
+
+    #[test]
+    fn select_range_with_exact_bound() {
+        let db = DB::new(vec![1, 2, 3, 2, 1]);
+        assert_eq!(sorted_values(&db.select_range(|&x| x, Bound::Exact(2))), vec![2, 2]);
+    }
+
+    #[test]
+    fn select_range_indexed_matches_select_range() {
+        let db = DB::new(vec![1, 2, 3, 4, 5]);
+        let index = db.build_index(|&x| x);
+
+        assert_eq!(
+            sorted_values(&db.select_range_indexed(&index, Bound::Inclusive(3))),
+            sorted_values(&db.select_range(|&x| x, Bound::Inclusive(3))),
+        );
+        assert_eq!(
+            sorted_values(&db.select_range_indexed(&index, Bound::Exclusive(3))),
+            sorted_values(&db.select_range(|&x| x, Bound::Exclusive(3))),
+        );
+        assert_eq!(
+            sorted_values(&db.select_range_indexed(&index, Bound::Exact(3))),
+            sorted_values(&db.select_range(|&x| x, Bound::Exact(3))),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "DBIndex was built from a DB of a different length")]
+    fn select_range_indexed_panics_on_stale_index() {
+        let built_from = DB::new(vec![1, 2, 3]);
+        let index = built_from.build_index(|&x| x);
+        let other = DB::new(vec![1, 2]);
+        other.select_range_indexed(&index, Bound::Inclusive(2));
+    }
+
+    #[test]
+    fn select_range_with_named_bool_null_unit_constructors() {
+        let flags = DB::new(vec![true, false, true]);
+        assert_eq!(flags.select_range(|&b| b, Bound::boolean(true)).len(), 2);
+
+        let units = DB::new(vec![(), (), ()]);
+        assert_eq!(units.select_range(|&u| u, Bound::unit()).len(), 3);
+
+        let maybe_ids = DB::new(vec![Some(1), None, Some(2), None]);
+        assert_eq!(maybe_ids.select_range(|&id| id, Bound::null()).len(), 2);
+    }
+}